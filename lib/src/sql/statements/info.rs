@@ -6,48 +6,344 @@ use crate::err::Error;
 use crate::sql::comment::shouldbespace;
 use crate::sql::error::IResult;
 use crate::sql::ident::ident_raw;
+use crate::sql::object::Object;
+use crate::sql::statements::DefineFieldStatement;
 use crate::sql::value::Value;
 use derive::Store;
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
+use nom::combinator::{map, opt};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// The output format requested for an `INFO FOR ...` statement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum InfoStructure {
+	/// Emit each definition as its reconstructed `DEFINE` DDL string.
+	Sql,
+	/// Emit each definition as an object. Field definitions are split into
+	/// their name, type, assertion and permissions, each as SurrealQL text;
+	/// other definitions are not decomposed and carry only their name.
+	Json,
+}
+
+impl Default for InfoStructure {
+	fn default() -> Self {
+		Self::Sql
+	}
+}
+
+impl fmt::Display for InfoStructure {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Sql => write!(f, "SQL"),
+			Self::Json => write!(f, "JSON"),
+		}
+	}
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Store)]
 pub enum InfoStatement {
-	Namespace,
-	Database,
-	Scope(String),
-	Table(String),
+	Kv(InfoStructure),
+	Namespace(InfoStructure),
+	Database(InfoStructure),
+	Scope(String, InfoStructure),
+	Table(String, InfoStructure),
+	User(String, Level),
 }
 
 impl InfoStatement {
 	pub async fn compute(
 		&self,
-		ctx: &Runtime,
+		_ctx: &Runtime,
 		opt: &Options,
 		txn: &Transaction,
 		_doc: Option<&Value>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
 		match self {
-			InfoStatement::Namespace => opt.check(Level::Ns)?,
-			InfoStatement::Database => opt.check(Level::Db)?,
-			InfoStatement::Scope(_) => opt.check(Level::Db)?,
-			InfoStatement::Table(_) => opt.check(Level::Db)?,
+			InfoStatement::Kv(_) => opt.check(Level::Kv)?,
+			InfoStatement::Namespace(_) => opt.check(Level::Ns)?,
+			InfoStatement::Database(_) => opt.check(Level::Db)?,
+			InfoStatement::Scope(_, _) => opt.check(Level::Db)?,
+			InfoStatement::Table(_, _) => opt.check(Level::Db)?,
+			InfoStatement::User(_, level) => opt.check(*level)?,
 		}
 		// Continue
-		todo!()
+		match self {
+			InfoStatement::Kv(structure) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Process the namespaces
+				let mut tmp = Object::default();
+				for v in run.all_ns().await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("ns".to_owned(), tmp.into());
+				// Process the root users
+				let mut tmp = Object::default();
+				for v in run.all_rl().await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("rl".to_owned(), tmp.into());
+				// Ok all good
+				Ok(Value::from(res))
+			}
+			InfoStatement::Namespace(structure) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Process the databases
+				let mut tmp = Object::default();
+				for v in run.all_db(opt.ns()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("db".to_owned(), tmp.into());
+				// Process the logins
+				let mut tmp = Object::default();
+				for v in run.all_nl(opt.ns()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("nl".to_owned(), tmp.into());
+				// Process the tokens
+				let mut tmp = Object::default();
+				for v in run.all_nt(opt.ns()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("nt".to_owned(), tmp.into());
+				// Ok all good
+				Ok(Value::from(res))
+			}
+			InfoStatement::Database(structure) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Process the tables
+				let mut tmp = Object::default();
+				for v in run.all_tb(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("tb".to_owned(), tmp.into());
+				// Process the scopes
+				let mut tmp = Object::default();
+				for v in run.all_sc(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("sc".to_owned(), tmp.into());
+				// Process the logins
+				let mut tmp = Object::default();
+				for v in run.all_dl(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("dl".to_owned(), tmp.into());
+				// Process the tokens
+				let mut tmp = Object::default();
+				for v in run.all_dt(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("dt".to_owned(), tmp.into());
+				// Ok all good
+				Ok(Value::from(res))
+			}
+			InfoStatement::Scope(sc, structure) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Fetch the scope definition
+				let sv = run.get_sc(opt.ns(), opt.db(), sc).await?;
+				// Process the signin clause
+				res.insert(
+					"signin".to_owned(),
+					match (&sv.signin, structure) {
+						(Some(v), InfoStructure::Sql) => v.to_string().into(),
+						(Some(v), InfoStructure::Json) => v.to_owned(),
+						(None, _) => Value::None,
+					},
+				);
+				// Process the signup clause
+				res.insert(
+					"signup".to_owned(),
+					match (&sv.signup, structure) {
+						(Some(v), InfoStructure::Sql) => v.to_string().into(),
+						(Some(v), InfoStructure::Json) => v.to_owned(),
+						(None, _) => Value::None,
+					},
+				);
+				// Process the session duration
+				res.insert(
+					"session".to_owned(),
+					match sv.session {
+						Some(v) => v.into(),
+						None => Value::None,
+					},
+				);
+				// Ok all good
+				Ok(Value::from(res))
+			}
+			InfoStatement::Table(tb, structure) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Process the fields
+				let mut tmp = Object::default();
+				for v in run.all_fd(opt.ns(), opt.db(), tb).await?.iter() {
+					let val = match structure {
+						InfoStructure::Sql => v.to_string().into(),
+						InfoStructure::Json => field_structure(v).into(),
+					};
+					tmp.insert(v.name.to_string(), val);
+				}
+				res.insert("fd".to_owned(), tmp.into());
+				// Process the events
+				let mut tmp = Object::default();
+				for v in run.all_ev(opt.ns(), opt.db(), tb).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("ev".to_owned(), tmp.into());
+				// Process the indexes
+				let mut tmp = Object::default();
+				for v in run.all_ix(opt.ns(), opt.db(), tb).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("ix".to_owned(), tmp.into());
+				// Process the foreign tables
+				let mut tmp = Object::default();
+				for v in run.all_ft(opt.ns(), opt.db(), tb).await?.iter() {
+					tmp.insert(v.name.to_raw(), export(&v.name.to_raw(), v, *structure));
+				}
+				res.insert("ft".to_owned(), tmp.into());
+				// Ok all good
+				Ok(Value::from(res))
+			}
+			InfoStatement::User(user, level) => {
+				// Get the transaction
+				let mut run = txn.lock().await;
+				// Create the result set
+				let mut res = Object::default();
+				// Fetch the matching login and token definitions
+				let (lg, tk) = match level {
+					Level::Ns => {
+						let lg = run.get_nl(opt.ns(), user).await?;
+						let tk = match run.get_nt(opt.ns(), user).await {
+							Ok(tk) => Some(tk),
+							Err(Error::NtNotFound {
+								..
+							}) => None,
+							Err(e) => return Err(e),
+						};
+						(lg, tk)
+					}
+					Level::Db => {
+						let lg = run.get_dl(opt.ns(), opt.db(), user).await?;
+						let tk = match run.get_dt(opt.ns(), opt.db(), user).await {
+							Ok(tk) => Some(tk),
+							Err(Error::DtNotFound {
+								..
+							}) => None,
+							Err(e) => return Err(e),
+						};
+						(lg, tk)
+					}
+					_ => return Err(Error::InvalidAuth),
+				};
+				// Process the roles
+				res.insert(
+					"roles".to_owned(),
+					lg.roles.iter().map(|r| r.to_string().into()).collect::<Vec<Value>>().into(),
+				);
+				// Process the configured authentication scope
+				res.insert("scope".to_owned(), lg.base.to_string().into());
+				// Process the token issuer
+				res.insert(
+					"issuer".to_owned(),
+					match &tk {
+						Some(tk) => tk.issuer.to_owned().into(),
+						None => Value::None,
+					},
+				);
+				// Only the root account may view secret material
+				if opt.check(Level::Kv).is_ok() {
+					res.insert("hash".to_owned(), lg.hash.to_owned().into());
+					res.insert(
+						"code".to_owned(),
+						match &tk {
+							Some(tk) => tk.code.to_owned().into(),
+							None => Value::None,
+						},
+					);
+				}
+				// Ok all good
+				Ok(Value::from(res))
+			}
+		}
+	}
+}
+
+/// Render a single catalog entry either as its reconstructed `DEFINE` DDL
+/// string, or as an object holding only the entry's name, depending on the
+/// requested `InfoStructure`. No other part of the definition is included in
+/// the object.
+fn export(name: &str, def: &impl fmt::Display, structure: InfoStructure) -> Value {
+	match structure {
+		InfoStructure::Sql => def.to_string().into(),
+		InfoStructure::Json => {
+			let mut res = Object::default();
+			res.insert("name".to_owned(), name.to_owned().into());
+			res.into()
+		}
 	}
 }
 
+/// Decompose a field definition into its typed parts, for `INFO ... AS JSON`
+/// output, so that clients can read the type, assertion and permissions of a
+/// field without re-parsing its reconstructed `DEFINE FIELD` DDL string.
+fn field_structure(def: &DefineFieldStatement) -> Object {
+	let mut res = Object::default();
+	res.insert("name".to_owned(), def.name.to_string().into());
+	res.insert(
+		"type".to_owned(),
+		match &def.kind {
+			Some(k) => k.to_string().into(),
+			None => Value::None,
+		},
+	);
+	res.insert(
+		"assert".to_owned(),
+		match &def.assert {
+			Some(v) => v.to_string().into(),
+			None => Value::None,
+		},
+	);
+	res.insert("permissions".to_owned(), def.permissions.to_string().into());
+	res
+}
+
 impl fmt::Display for InfoStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self {
-			InfoStatement::Namespace => write!(f, "INFO FOR NAMESPACE"),
-			InfoStatement::Database => write!(f, "INFO FOR DATABASE"),
-			InfoStatement::Scope(ref s) => write!(f, "INFO FOR SCOPE {}", s),
-			InfoStatement::Table(ref t) => write!(f, "INFO FOR TABLE {}", t),
+			InfoStatement::Kv(structure) => write!(f, "INFO FOR KV AS {}", structure),
+			InfoStatement::Namespace(structure) => write!(f, "INFO FOR NAMESPACE AS {}", structure),
+			InfoStatement::Database(structure) => write!(f, "INFO FOR DATABASE AS {}", structure),
+			InfoStatement::Scope(ref s, structure) => {
+				write!(f, "INFO FOR SCOPE {} AS {}", s, structure)
+			}
+			InfoStatement::Table(ref t, structure) => {
+				write!(f, "INFO FOR TABLE {} AS {}", t, structure)
+			}
+			InfoStatement::User(ref u, ref level) => {
+				let level = match level {
+					Level::Ns => "NAMESPACE",
+					Level::Db => "DATABASE",
+					_ => "KV",
+				};
+				write!(f, "INFO FOR USER {} ON {}", u, level)
+			}
 		}
 	}
 }
@@ -57,37 +353,93 @@ pub fn info(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("FOR")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	alt((namespace, database, scope, table))(i)
+	alt((kv, namespace, database, scope, table, user))(i)
+}
+
+fn structure(i: &str) -> IResult<&str, InfoStructure> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("AS")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	alt((
+		map(tag_no_case("JSON"), |_| InfoStructure::Json),
+		map(tag_no_case("SQL"), |_| InfoStructure::Sql),
+	))(i)
+}
+
+fn kv(i: &str) -> IResult<&str, InfoStatement> {
+	let (i, _) = alt((tag_no_case("KV"), tag_no_case("ROOT")))(i)?;
+	let (i, structure) = opt(structure)(i)?;
+	Ok((i, InfoStatement::Kv(structure.unwrap_or_default())))
 }
 
 fn namespace(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = alt((tag_no_case("NAMESPACE"), tag_no_case("NS")))(i)?;
-	Ok((i, InfoStatement::Namespace))
+	let (i, structure) = opt(structure)(i)?;
+	Ok((i, InfoStatement::Namespace(structure.unwrap_or_default())))
 }
 
 fn database(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = alt((tag_no_case("DATABASE"), tag_no_case("DB")))(i)?;
-	Ok((i, InfoStatement::Database))
+	let (i, structure) = opt(structure)(i)?;
+	Ok((i, InfoStatement::Database(structure.unwrap_or_default())))
 }
 
 fn scope(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = alt((tag_no_case("SCOPE"), tag_no_case("SC")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, scope) = ident_raw(i)?;
-	Ok((i, InfoStatement::Scope(scope)))
+	let (i, structure) = opt(structure)(i)?;
+	Ok((i, InfoStatement::Scope(scope, structure.unwrap_or_default())))
 }
 
 fn table(i: &str) -> IResult<&str, InfoStatement> {
 	let (i, _) = alt((tag_no_case("TABLE"), tag_no_case("TB")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, table) = ident_raw(i)?;
-	Ok((i, InfoStatement::Table(table)))
+	let (i, structure) = opt(structure)(i)?;
+	Ok((i, InfoStatement::Table(table, structure.unwrap_or_default())))
+}
+
+fn user(i: &str) -> IResult<&str, InfoStatement> {
+	let (i, _) = tag_no_case("USER")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, user) = ident_raw(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ON")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, level) = alt((
+		map(alt((tag_no_case("NAMESPACE"), tag_no_case("NS"))), |_| Level::Ns),
+		map(alt((tag_no_case("DATABASE"), tag_no_case("DB"))), |_| Level::Db),
+	))(i)?;
+	Ok((i, InfoStatement::User(user, level)))
 }
 
 #[cfg(test)]
 mod tests {
 
 	use super::*;
+	use crate::dbs::Session;
+	use crate::kvs::Datastore;
+
+	#[test]
+	fn info_query_kv() {
+		let sql = "INFO FOR KV";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::Kv(InfoStructure::Sql));
+		assert_eq!("INFO FOR KV AS SQL", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_root() {
+		let sql = "INFO FOR ROOT";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::Kv(InfoStructure::Sql));
+		assert_eq!("INFO FOR KV AS SQL", format!("{}", out));
+	}
 
 	#[test]
 	fn info_query_ns() {
@@ -95,8 +447,8 @@ mod tests {
 		let res = info(sql);
 		assert!(res.is_ok());
 		let out = res.unwrap().1;
-		assert_eq!(out, InfoStatement::Namespace);
-		assert_eq!("INFO FOR NAMESPACE", format!("{}", out));
+		assert_eq!(out, InfoStatement::Namespace(InfoStructure::Sql));
+		assert_eq!("INFO FOR NAMESPACE AS SQL", format!("{}", out));
 	}
 
 	#[test]
@@ -105,8 +457,8 @@ mod tests {
 		let res = info(sql);
 		assert!(res.is_ok());
 		let out = res.unwrap().1;
-		assert_eq!(out, InfoStatement::Database);
-		assert_eq!("INFO FOR DATABASE", format!("{}", out));
+		assert_eq!(out, InfoStatement::Database(InfoStructure::Sql));
+		assert_eq!("INFO FOR DATABASE AS SQL", format!("{}", out));
 	}
 
 	#[test]
@@ -115,8 +467,8 @@ mod tests {
 		let res = info(sql);
 		assert!(res.is_ok());
 		let out = res.unwrap().1;
-		assert_eq!(out, InfoStatement::Scope(String::from("test")));
-		assert_eq!("INFO FOR SCOPE test", format!("{}", out));
+		assert_eq!(out, InfoStatement::Scope(String::from("test"), InfoStructure::Sql));
+		assert_eq!("INFO FOR SCOPE test AS SQL", format!("{}", out));
 	}
 
 	#[test]
@@ -125,7 +477,156 @@ mod tests {
 		let res = info(sql);
 		assert!(res.is_ok());
 		let out = res.unwrap().1;
-		assert_eq!(out, InfoStatement::Table(String::from("test")));
-		assert_eq!("INFO FOR TABLE test", format!("{}", out));
+		assert_eq!(out, InfoStatement::Table(String::from("test"), InfoStructure::Sql));
+		assert_eq!("INFO FOR TABLE test AS SQL", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_tb_as_json() {
+		let sql = "INFO FOR TABLE test AS JSON";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::Table(String::from("test"), InfoStructure::Json));
+		assert_eq!("INFO FOR TABLE test AS JSON", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_user_ns() {
+		let sql = "INFO FOR USER test ON NAMESPACE";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::User(String::from("test"), Level::Ns));
+		assert_eq!("INFO FOR USER test ON NAMESPACE", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_user_db() {
+		let sql = "INFO FOR USER test ON DATABASE";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::User(String::from("test"), Level::Db));
+		assert_eq!("INFO FOR USER test ON DATABASE", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_user_ns_abbr() {
+		let sql = "INFO FOR USER test ON NS";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::User(String::from("test"), Level::Ns));
+		assert_eq!("INFO FOR USER test ON NAMESPACE", format!("{}", out));
+	}
+
+	#[test]
+	fn info_query_user_db_abbr() {
+		let sql = "INFO FOR USER test ON DB";
+		let res = info(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, InfoStatement::User(String::from("test"), Level::Db));
+		assert_eq!("INFO FOR USER test ON DATABASE", format!("{}", out));
+	}
+
+	#[tokio::test]
+	async fn info_compute_tb_as_json_decomposes_fields() {
+		let sql = "
+			DEFINE TABLE person SCHEMALESS;
+			DEFINE FIELD name ON person TYPE string ASSERT $value != NONE PERMISSIONS FULL;
+			INFO FOR TABLE person AS JSON;
+		";
+		let dbs = Datastore::new("memory").await.unwrap();
+		let ses = Session::for_kv().with_ns("test").with_db("test");
+		let res = &mut dbs.execute(sql, &ses, None, false).await.unwrap();
+		assert_eq!(res.len(), 3);
+		let tmp = res.remove(2).result.unwrap();
+		let obj = match tmp {
+			Value::Object(v) => v,
+			v => panic!("Expected an object, found: {:?}", v),
+		};
+		let fd = match obj.get("fd") {
+			Some(Value::Object(v)) => v,
+			v => panic!("Expected a fields object, found: {:?}", v),
+		};
+		let name = match fd.get("name") {
+			Some(Value::Object(v)) => v,
+			v => panic!("Expected a decomposed field object, found: {:?}", v),
+		};
+		assert!(name.contains_key("type"));
+		assert!(name.contains_key("assert"));
+		assert!(name.contains_key("permissions"));
+	}
+
+	#[tokio::test]
+	async fn info_compute_ns_as_json_decomposes_entries() {
+		let sql = "
+			DEFINE DATABASE test;
+			INFO FOR NAMESPACE AS JSON;
+		";
+		let dbs = Datastore::new("memory").await.unwrap();
+		let ses = Session::for_kv().with_ns("test").with_db("test");
+		let res = &mut dbs.execute(sql, &ses, None, false).await.unwrap();
+		assert_eq!(res.len(), 2);
+		let tmp = res.remove(1).result.unwrap();
+		let obj = match tmp {
+			Value::Object(v) => v,
+			v => panic!("Expected an object, found: {:?}", v),
+		};
+		let db = match obj.get("db") {
+			Some(Value::Object(v)) => v,
+			v => panic!("Expected a databases object, found: {:?}", v),
+		};
+		match db.get("test") {
+			Some(Value::Object(v)) => {
+				assert_eq!(v.get("name"), Some(&Value::from("test")));
+			}
+			v => panic!("Expected a decomposed database object, found: {:?}", v),
+		}
+	}
+
+	#[tokio::test]
+	async fn info_compute_user_redacts_secrets_for_non_root() {
+		let sql = "
+			DEFINE LOGIN test ON DATABASE PASSWORD 'pass';
+			DEFINE TOKEN test ON DATABASE TYPE HS512 VALUE 'secret';
+			INFO FOR USER test ON DATABASE;
+		";
+		let dbs = Datastore::new("memory").await.unwrap();
+		let ses = Session::for_db().with_ns("test").with_db("test");
+		let res = &mut dbs.execute(sql, &ses, None, false).await.unwrap();
+		assert_eq!(res.len(), 3);
+		let tmp = res.remove(2).result.unwrap();
+		let obj = match tmp {
+			Value::Object(v) => v,
+			v => panic!("Expected an object, found: {:?}", v),
+		};
+		assert!(obj.contains_key("roles"));
+		assert!(obj.contains_key("scope"));
+		assert!(obj.contains_key("issuer"));
+		assert!(!obj.contains_key("hash"));
+		assert!(!obj.contains_key("code"));
+	}
+
+	#[tokio::test]
+	async fn info_compute_user_reveals_secrets_for_root() {
+		let sql = "
+			DEFINE LOGIN test ON DATABASE PASSWORD 'pass';
+			DEFINE TOKEN test ON DATABASE TYPE HS512 VALUE 'secret';
+			INFO FOR USER test ON DATABASE;
+		";
+		let dbs = Datastore::new("memory").await.unwrap();
+		let ses = Session::for_kv().with_ns("test").with_db("test");
+		let res = &mut dbs.execute(sql, &ses, None, false).await.unwrap();
+		assert_eq!(res.len(), 3);
+		let tmp = res.remove(2).result.unwrap();
+		let obj = match tmp {
+			Value::Object(v) => v,
+			v => panic!("Expected an object, found: {:?}", v),
+		};
+		assert!(obj.contains_key("hash"));
+		assert!(obj.contains_key("code"));
 	}
 }